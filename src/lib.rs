@@ -1,8 +1,152 @@
-use chrono::{DateTime, Datelike, Duration, Local, NaiveTime, TimeZone};
+use chrono::format::{Item, StrftimeItems};
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, Local, NaiveDate, NaiveTime, TimeZone, Weekday,
+};
 use indicatif::{ProgressBar, ProgressStyle};
 use std::time::Duration as StdDuration;
 
-pub fn parse_end_time(args: &[String], now: DateTime<Local>) -> Result<DateTime<Local>, String> {
+/// Seconds represented by a single duration unit word, supporting both the
+/// spelled-out forms and their short/single-letter abbreviations.
+fn unit_secs(unit: &str) -> Option<i64> {
+    match unit {
+        "second" | "seconds" | "sec" | "secs" | "s" => Some(1),
+        "minute" | "minutes" | "min" | "mins" | "m" => Some(60),
+        "hour" | "hours" | "hr" | "hrs" | "h" => Some(3600),
+        "day" | "days" | "d" => Some(86400),
+        "week" | "weeks" | "w" => Some(604800),
+        _ => None,
+    }
+}
+
+/// Parse a fixed UTC offset (`+0900`, `-0500`, or `Z`) into a [`FixedOffset`].
+fn parse_offset(s: &str) -> Option<FixedOffset> {
+    if s.eq_ignore_ascii_case("Z") {
+        return FixedOffset::east_opt(0);
+    }
+    let (sign, digits) = match s.split_at(1) {
+        ("+", rest) => (1, rest),
+        ("-", rest) => (-1, rest),
+        _ => return None,
+    };
+    if digits.len() != 4 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let hours: i32 = digits[0..2].parse().ok()?;
+    let mins: i32 = digits[2..4].parse().ok()?;
+    FixedOffset::east_opt(sign * (hours * 3600 + mins * 60))
+}
+
+/// Map a lowercase weekday name (full or abbreviated) to its [`Weekday`].
+fn parse_weekday(s: &str) -> Option<Weekday> {
+    match s {
+        "mon" | "monday" => Some(Weekday::Mon),
+        "tue" | "tuesday" => Some(Weekday::Tue),
+        "wed" | "wednesday" => Some(Weekday::Wed),
+        "thu" | "thursday" => Some(Weekday::Thu),
+        "fri" | "friday" => Some(Weekday::Fri),
+        "sat" | "saturday" => Some(Weekday::Sat),
+        "sun" | "sunday" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// Resolve a calendar keyword to a date, alongside the step to roll forward by
+/// when the combined time has already passed (`None` for explicit calendar
+/// offsets like `tomorrow`/`yesterday`, which never roll).
+fn resolve_date_keyword<Tz: TimeZone>(
+    kw: &str,
+    now: &DateTime<Tz>,
+) -> Option<(NaiveDate, Option<Duration>)> {
+    let today = now.date_naive();
+    match kw {
+        "today" => Some((today, Some(Duration::days(1)))),
+        "tomorrow" => Some((today + Duration::days(1), None)),
+        "yesterday" => Some((today - Duration::days(1), None)),
+        _ => {
+            let target = parse_weekday(kw)?;
+            let diff = (target.num_days_from_monday() as i64
+                - now.weekday().num_days_from_monday() as i64)
+                .rem_euclid(7);
+            Some((today + Duration::days(diff), Some(Duration::days(7))))
+        }
+    }
+}
+
+/// Parse a single duration token (`30m`, `1h`, `2d`, or a plain second count)
+/// into a [`Duration`], reusing the same unit vocabulary as [`parse_end_time`].
+fn parse_duration(s: &str) -> Option<Duration> {
+    if let Ok(secs) = s.parse::<i64>() {
+        return Some(Duration::seconds(secs));
+    }
+    let split_at = s.find(|c: char| c.is_alphabetic()).unwrap_or(0);
+    let (num_part, unit_part) = s.split_at(split_at);
+    let per = unit_secs(&unit_part.to_ascii_lowercase())?;
+    let count = num_part.parse::<i64>().ok()?;
+    Some(Duration::seconds(count * per))
+}
+
+/// How many times a [`RecurrenceSpec`] fires.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Limit {
+    /// Fire exactly this many times.
+    Times(u32),
+    /// Keep firing until the next target would pass this instant.
+    Until(DateTime<Local>),
+}
+
+/// A repeating sleep: fire every `interval` until `limit` is reached.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecurrenceSpec {
+    pub interval: Duration,
+    pub limit: Limit,
+}
+
+impl RecurrenceSpec {
+    /// Yield the successive end times `start + interval*1`, `start + interval*2`,
+    /// … stopping after [`Limit::Times`] iterations or once the next target would
+    /// exceed [`Limit::Until`].
+    pub fn iter(&self, start: DateTime<Local>) -> RecurrenceIter {
+        RecurrenceIter {
+            start,
+            spec: self.clone(),
+            n: 0,
+        }
+    }
+}
+
+/// Iterator over the end times produced by a [`RecurrenceSpec`].
+pub struct RecurrenceIter {
+    start: DateTime<Local>,
+    spec: RecurrenceSpec,
+    n: u32,
+}
+
+impl Iterator for RecurrenceIter {
+    type Item = DateTime<Local>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.n += 1;
+        let target = self.start + self.spec.interval * self.n as i32;
+        match self.spec.limit {
+            Limit::Times(times) if self.n > times => None,
+            // A non-positive interval never advances past `until`, which would
+            // loop forever; stop rather than yield unbounded targets.
+            Limit::Until(until) if target > until || self.spec.interval <= Duration::zero() => None,
+            _ => Some(target),
+        }
+    }
+}
+
+/// Parse the time arguments into an absolute wake time, expressed in local time.
+///
+/// `now` carries the reference instant *and* the target timezone: clock-time and
+/// calendar-keyword targets are built in `now`'s zone (so the "past → roll
+/// forward" logic is evaluated there) and converted back to [`Local`] for the
+/// sleep computation. Passing a `DateTime<Local>` keeps the default behavior.
+pub fn parse_end_time<Tz: TimeZone>(
+    args: &[String],
+    now: DateTime<Tz>,
+) -> Result<DateTime<Local>, String> {
     if args.is_empty() {
         return Err("no arguments provided".to_string());
     }
@@ -11,48 +155,84 @@ pub fn parse_end_time(args: &[String], now: DateTime<Local>) -> Result<DateTime<
     if args.len() == 1
         && let Ok(secs) = args[0].parse::<u64>()
     {
-        return Ok(now + Duration::seconds(secs as i64));
+        return Ok((now + Duration::seconds(secs as i64)).with_timezone(&Local));
     }
 
-    // 2. One or more tokens with h/m/s suffixes -> sum durations
+    // 2. One or more duration tokens -> sum durations. Each token is either a
+    //    suffixed value (`2h`, `1w`), a spelled-out unit (`45 sec`, `2 days`),
+    //    or a bare number feeding the unit word that follows it.
     {
         let mut total_secs: i64 = 0;
+        let mut pending: Option<i64> = None;
         let mut all_matched = true;
         for token in args {
-            if let Some(val) = token.strip_suffix('h') {
-                match val.parse::<i64>() {
-                    Ok(n) => total_secs += n * 3600,
-                    Err(_) => {
-                        all_matched = false;
-                        break;
-                    }
+            // A bare number carries over to the next token's unit word.
+            if let Ok(n) = token.parse::<i64>() {
+                if pending.is_some() {
+                    all_matched = false;
+                    break;
                 }
-            } else if let Some(val) = token.strip_suffix('m') {
-                match val.parse::<i64>() {
-                    Ok(n) => total_secs += n * 60,
-                    Err(_) => {
+                pending = Some(n);
+                continue;
+            }
+            // Split into an optional numeric prefix and a unit word.
+            let split_at = token.find(|c: char| c.is_alphabetic()).unwrap_or(0);
+            let (num_part, unit_part) = token.split_at(split_at);
+            let Some(per) = unit_secs(&unit_part.to_ascii_lowercase()) else {
+                all_matched = false;
+                break;
+            };
+            let count = if num_part.is_empty() {
+                match pending.take() {
+                    Some(n) => n,
+                    None => {
                         all_matched = false;
                         break;
                     }
                 }
-            } else if let Some(val) = token.strip_suffix('s') {
-                match val.parse::<i64>() {
-                    Ok(n) => total_secs += n,
+            } else {
+                match num_part.parse::<i64>() {
+                    Ok(n) => n,
                     Err(_) => {
                         all_matched = false;
                         break;
                     }
                 }
-            } else {
-                all_matched = false;
-                break;
-            }
+            };
+            total_secs += count * per;
+        }
+        // A trailing bare number with no unit word is not a valid duration.
+        if pending.is_some() {
+            all_matched = false;
         }
         if all_matched && !args.is_empty() {
-            return Ok(now + Duration::seconds(total_secs));
+            return Ok((now + Duration::seconds(total_secs)).with_timezone(&Local));
         }
     }
 
+    // 2b. Calendar keyword (today/tomorrow/yesterday or a weekday name) with an
+    //     optional trailing HH:MM / HH:MM:SS token.
+    if args.len() <= 2
+        && let Some((date, roll)) = resolve_date_keyword(&args[0].to_ascii_lowercase(), &now)
+    {
+        let time = match args.get(1) {
+            Some(tok) => NaiveTime::parse_from_str(tok, "%H:%M")
+                .or_else(|_| NaiveTime::parse_from_str(tok, "%H:%M:%S"))
+                .map_err(|_| format!("could not parse time: {}", tok))?,
+            None => NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
+        };
+        let naive_dt = date.and_time(time);
+        let mut end = now
+            .timezone()
+            .from_local_datetime(&naive_dt)
+            .single()
+            .ok_or_else(|| "failed to convert local datetime".to_string())?;
+        if end <= now && let Some(step) = roll {
+            end += step;
+        }
+        return Ok(end.with_timezone(&Local));
+    }
+
     // All remaining formats expect exactly one argument
     if args.len() != 1 {
         return Err(format!("could not parse arguments: {:?}", args));
@@ -62,29 +242,25 @@ pub fn parse_end_time(args: &[String], now: DateTime<Local>) -> Result<DateTime<
     // 3. HH:MM -> today at that time; if in the past, tomorrow
     if let Ok(t) = NaiveTime::parse_from_str(s, "%H:%M") {
         let naive_dt = now.date_naive().and_time(t);
-        let end = Local
+        let end = now
+            .timezone()
             .from_local_datetime(&naive_dt)
             .single()
             .ok_or_else(|| "failed to convert local datetime".to_string())?;
-        return Ok(if end <= now {
-            end + Duration::days(1)
-        } else {
-            end
-        });
+        let end = if end <= now { end + Duration::days(1) } else { end };
+        return Ok(end.with_timezone(&Local));
     }
 
     // 4. HH:MM:SS -> today at that time; if in the past, tomorrow
     if let Ok(t) = NaiveTime::parse_from_str(s, "%H:%M:%S") {
         let naive_dt = now.date_naive().and_time(t);
-        let end = Local
+        let end = now
+            .timezone()
             .from_local_datetime(&naive_dt)
             .single()
             .ok_or_else(|| "failed to convert local datetime".to_string())?;
-        return Ok(if end <= now {
-            end + Duration::days(1)
-        } else {
-            end
-        });
+        let end = if end <= now { end + Duration::days(1) } else { end };
+        return Ok(end.with_timezone(&Local));
     }
 
     // 5. ISO 8601 with timezone offset: YYYYMMDDThhmmss+HHMM / -HHMM
@@ -104,6 +280,17 @@ pub fn parse_end_time(args: &[String], now: DateTime<Local>) -> Result<DateTime<
     Err(format!("could not parse argument: {}", s))
 }
 
+/// Ensure a user-supplied strftime template is well-formed before it is used in
+/// the render loop, so a bad specifier fails cleanly up front rather than
+/// panicking mid-render.
+pub fn validate_format(fmt: &str) -> Result<(), String> {
+    if StrftimeItems::new(fmt).any(|item| matches!(item, Item::Error)) {
+        Err(format!("invalid format string: {}", fmt))
+    } else {
+        Ok(())
+    }
+}
+
 pub fn format_eta(end: &DateTime<Local>, now: &DateTime<Local>) -> String {
     let end_date = end.date_naive();
     let now_date = now.date_naive();
@@ -117,7 +304,16 @@ pub fn format_eta(end: &DateTime<Local>, now: &DateTime<Local>) -> String {
     }
 }
 
-pub async fn sleep_until_with_progress(end_time: DateTime<Local>) {
+/// Render the ETA, preferring a user-supplied strftime template over the
+/// adaptive [`format_eta`] shape when one is given.
+fn render_eta(end: &DateTime<Local>, now: &DateTime<Local>, format: Option<&str>) -> String {
+    match format {
+        Some(fmt) => end.format(fmt).to_string(),
+        None => format_eta(end, now),
+    }
+}
+
+pub async fn sleep_until_with_progress(end_time: DateTime<Local>, format: Option<&str>) {
     let start_time = Local::now();
     let total_ms = (end_time - start_time).num_milliseconds().max(1000);
     let total_secs = (total_ms as u64).div_ceil(1000); // ceil
@@ -129,7 +325,7 @@ pub async fn sleep_until_with_progress(end_time: DateTime<Local>) {
             .progress_chars("█░"),
     );
 
-    let eta_str = format_eta(&end_time, &Local::now());
+    let eta_str = render_eta(&end_time, &Local::now(), format);
     pb.set_message(format!(
         "{:02}:{:02}:{:02} | ETA {}",
         total_secs / 3600,
@@ -153,7 +349,7 @@ pub async fn sleep_until_with_progress(end_time: DateTime<Local>) {
         last_elapsed_secs = elapsed_secs;
         pb.set_position(elapsed_secs.min(total_secs));
         let remaining_secs = (remaining as f64 / 1000.0).ceil() as i64;
-        let eta_str = format_eta(&end_time, &Local::now());
+        let eta_str = render_eta(&end_time, &Local::now(), format);
         pb.set_message(format!(
             "{:02}:{:02}:{:02} | ETA {}",
             remaining_secs / 3600,
@@ -172,22 +368,81 @@ async fn sleep_until_without_progress(end_time: DateTime<Local>) {
     }
 }
 
-pub async fn sleep_until(end_time: DateTime<Local>, quiet: bool) {
+pub async fn sleep_until(end_time: DateTime<Local>, quiet: bool, format: Option<&str>) {
     if quiet {
         sleep_until_without_progress(end_time).await;
     } else {
-        sleep_until_with_progress(end_time).await;
+        sleep_until_with_progress(end_time, format).await;
     }
 }
 
-pub fn split_args(raw: &[String]) -> (bool, Vec<String>) {
-    let quiet = raw.iter().any(|a| a == "-q" || a == "--quiet");
-    let time_args = raw
-        .iter()
-        .filter(|a| *a != "-q" && *a != "--quiet")
-        .cloned()
-        .collect();
-    (quiet, time_args)
+/// Parsed command-line options: the quiet flag, an optional recurrence, and the
+/// remaining positional time arguments handed to [`parse_end_time`].
+#[derive(Debug, Default, PartialEq)]
+pub struct Options {
+    pub quiet: bool,
+    pub recurrence: Option<RecurrenceSpec>,
+    pub format: Option<String>,
+    pub tz: Option<FixedOffset>,
+    pub time_args: Vec<String>,
+}
+
+pub fn split_args(raw: &[String]) -> Result<Options, String> {
+    let mut opts = Options::default();
+    let mut every: Option<Duration> = None;
+    let mut times: Option<u32> = None;
+    let mut until: Option<DateTime<Local>> = None;
+
+    // Consume the value following a flag, erroring if it is missing.
+    let value = |i: usize, flag: &str| {
+        raw.get(i)
+            .ok_or_else(|| format!("{} requires a value", flag))
+    };
+
+    let mut i = 0;
+    while i < raw.len() {
+        match raw[i].as_str() {
+            "-q" | "--quiet" => opts.quiet = true,
+            "--every" => {
+                i += 1;
+                let v = value(i, "--every")?;
+                every = Some(parse_duration(v).ok_or_else(|| format!("invalid --every value: {}", v))?);
+            }
+            "--times" => {
+                i += 1;
+                let v = value(i, "--times")?;
+                times = Some(v.parse::<u32>().map_err(|_| format!("invalid --times value: {}", v))?);
+            }
+            "--until" => {
+                i += 1;
+                let v = value(i, "--until")?;
+                until = Some(
+                    parse_end_time(std::slice::from_ref(v), Local::now())
+                        .map_err(|_| format!("invalid --until value: {}", v))?,
+                );
+            }
+            "--format" => {
+                i += 1;
+                opts.format = Some(value(i, "--format")?.clone());
+            }
+            "--tz" => {
+                i += 1;
+                let v = value(i, "--tz")?;
+                opts.tz = Some(parse_offset(v).ok_or_else(|| format!("invalid --tz value: {}", v))?);
+            }
+            other => opts.time_args.push(other.to_string()),
+        }
+        i += 1;
+    }
+
+    opts.recurrence = every.map(|interval| {
+        let limit = match until {
+            Some(until) => Limit::Until(until),
+            None => Limit::Times(times.unwrap_or(1)),
+        };
+        RecurrenceSpec { interval, limit }
+    });
+    Ok(opts)
 }
 
 #[cfg(test)]
@@ -260,6 +515,53 @@ mod tests {
         assert_eq!((end - now).num_seconds(), 5445);
     }
 
+    #[test]
+    fn test_days() {
+        let now = now_fixed();
+        let end = parse_end_time(&args(&["2d"]), now).unwrap();
+        assert_eq!((end - now).num_seconds(), 172800);
+    }
+
+    #[test]
+    fn test_weeks() {
+        let now = now_fixed();
+        let end = parse_end_time(&args(&["1w"]), now).unwrap();
+        assert_eq!((end - now).num_seconds(), 604800);
+    }
+
+    #[test]
+    fn test_weeks_days_hours() {
+        let now = now_fixed();
+        let end = parse_end_time(&args(&["1w", "2d", "3h"]), now).unwrap();
+        assert_eq!((end - now).num_seconds(), 604800 + 172800 + 10800);
+    }
+
+    #[test]
+    fn test_spelled_out_units() {
+        let now = now_fixed();
+        let end = parse_end_time(&args(&["2", "days"]), now).unwrap();
+        assert_eq!((end - now).num_seconds(), 172800);
+        let end = parse_end_time(&args(&["30", "min"]), now).unwrap();
+        assert_eq!((end - now).num_seconds(), 1800);
+        let end = parse_end_time(&args(&["45", "sec"]), now).unwrap();
+        assert_eq!((end - now).num_seconds(), 45);
+        let end = parse_end_time(&args(&["3", "hrs"]), now).unwrap();
+        assert_eq!((end - now).num_seconds(), 10800);
+    }
+
+    #[test]
+    fn test_mixed_spelled_and_suffixed() {
+        let now = now_fixed();
+        let end = parse_end_time(&args(&["1", "week", "2d"]), now).unwrap();
+        assert_eq!((end - now).num_seconds(), 604800 + 172800);
+    }
+
+    #[test]
+    fn test_dangling_number_is_error() {
+        let now = now_fixed();
+        assert!(parse_end_time(&args(&["2h", "5"]), now).is_err());
+    }
+
     #[test]
     fn test_hhmm_future() {
         // now = 10:00:00, target = 12:30 -> same day
@@ -295,6 +597,70 @@ mod tests {
         assert_eq!(end.date_naive(), expected_date);
     }
 
+    #[test]
+    fn test_keyword_tomorrow_with_time() {
+        let now = now_fixed();
+        let end = parse_end_time(&args(&["tomorrow", "08:00"]), now).unwrap();
+        assert_eq!(end.date_naive(), now.date_naive() + Duration::days(1));
+        assert_eq!(end.format("%H:%M:%S").to_string(), "08:00:00");
+    }
+
+    #[test]
+    fn test_keyword_today_future() {
+        let now = now_fixed();
+        let end = parse_end_time(&args(&["today", "23:30"]), now).unwrap();
+        assert_eq!(end.date_naive(), now.date_naive());
+        assert_eq!(end.format("%H:%M:%S").to_string(), "23:30:00");
+    }
+
+    #[test]
+    fn test_keyword_today_past_rolls_forward() {
+        let now = now_fixed();
+        let end = parse_end_time(&args(&["today", "08:00"]), now).unwrap();
+        assert_eq!(end.date_naive(), now.date_naive() + Duration::days(1));
+    }
+
+    #[test]
+    fn test_keyword_yesterday() {
+        let now = now_fixed();
+        let end = parse_end_time(&args(&["yesterday"]), now).unwrap();
+        assert_eq!(end.date_naive(), now.date_naive() - Duration::days(1));
+        assert_eq!(end.format("%H:%M:%S").to_string(), "00:00:00");
+    }
+
+    #[test]
+    fn test_keyword_weekday_same_day_future() {
+        // now_fixed is a Friday at 10:00; "friday 17:00" stays today.
+        let now = now_fixed();
+        let end = parse_end_time(&args(&["friday", "17:00"]), now).unwrap();
+        assert_eq!(end.date_naive(), now.date_naive());
+        assert_eq!(end.format("%H:%M:%S").to_string(), "17:00:00");
+    }
+
+    #[test]
+    fn test_keyword_weekday_same_day_past_rolls_week() {
+        // "friday 08:00" has passed, so it rolls to next Friday.
+        let now = now_fixed();
+        let end = parse_end_time(&args(&["friday", "08:00"]), now).unwrap();
+        assert_eq!(end.date_naive(), now.date_naive() + Duration::days(7));
+    }
+
+    #[test]
+    fn test_keyword_weekday_next() {
+        // From Friday, the next Monday is three days out.
+        let now = now_fixed();
+        let end = parse_end_time(&args(&["mon", "09:00"]), now).unwrap();
+        assert_eq!(end.date_naive(), now.date_naive() + Duration::days(3));
+        assert_eq!(end.weekday(), Weekday::Mon);
+    }
+
+    #[test]
+    fn test_keyword_case_insensitive() {
+        let now = now_fixed();
+        let end = parse_end_time(&args(&["Tomorrow", "08:00"]), now).unwrap();
+        assert_eq!(end.date_naive(), now.date_naive() + Duration::days(1));
+    }
+
     #[test]
     fn test_iso8601_with_tz() {
         let now = now_fixed();
@@ -372,33 +738,152 @@ mod tests {
     #[test]
     fn test_split_args_short_flag_prefix() {
         let raw = args(&["-q", "3"]);
-        let (quiet, time_args) = split_args(&raw);
-        assert!(quiet);
-        assert_eq!(time_args, args(&["3"]));
+        let opts = split_args(&raw).unwrap();
+        assert!(opts.quiet);
+        assert_eq!(opts.time_args, args(&["3"]));
     }
 
     #[test]
     fn test_split_args_long_flag_suffix() {
         let raw = args(&["5m", "--quiet"]);
-        let (quiet, time_args) = split_args(&raw);
-        assert!(quiet);
-        assert_eq!(time_args, args(&["5m"]));
+        let opts = split_args(&raw).unwrap();
+        assert!(opts.quiet);
+        assert_eq!(opts.time_args, args(&["5m"]));
     }
 
     #[test]
     fn test_split_args_no_flag() {
         let raw = args(&["2h", "30m"]);
-        let (quiet, time_args) = split_args(&raw);
-        assert!(!quiet);
-        assert_eq!(time_args, args(&["2h", "30m"]));
+        let opts = split_args(&raw).unwrap();
+        assert!(!opts.quiet);
+        assert_eq!(opts.time_args, args(&["2h", "30m"]));
     }
 
     #[test]
     fn test_split_args_flag_between() {
         let raw = args(&["1h", "-q", "30m"]);
-        let (quiet, time_args) = split_args(&raw);
-        assert!(quiet);
-        assert_eq!(time_args, args(&["1h", "30m"]));
+        let opts = split_args(&raw).unwrap();
+        assert!(opts.quiet);
+        assert_eq!(opts.time_args, args(&["1h", "30m"]));
+    }
+
+    #[test]
+    fn test_split_args_every_times() {
+        let raw = args(&["--every", "30m", "--times", "5"]);
+        let opts = split_args(&raw).unwrap();
+        let rec = opts.recurrence.expect("recurrence parsed");
+        assert_eq!(rec.interval, Duration::minutes(30));
+        assert_eq!(rec.limit, Limit::Times(5));
+        assert!(opts.time_args.is_empty());
+    }
+
+    #[test]
+    fn test_split_args_every_defaults_to_once() {
+        let raw = args(&["--every", "1h"]);
+        let rec = split_args(&raw).unwrap().recurrence.expect("recurrence parsed");
+        assert_eq!(rec.interval, Duration::hours(1));
+        assert_eq!(rec.limit, Limit::Times(1));
+    }
+
+    #[test]
+    fn test_split_args_invalid_tz_errors() {
+        let raw = args(&["09:00", "--tz", "+9"]);
+        assert!(split_args(&raw).is_err());
+    }
+
+    #[test]
+    fn test_split_args_invalid_every_errors() {
+        let raw = args(&["--every", "soon", "--times", "5"]);
+        assert!(split_args(&raw).is_err());
+    }
+
+    #[test]
+    fn test_split_args_missing_flag_value_errors() {
+        let raw = args(&["--format"]);
+        assert!(split_args(&raw).is_err());
+    }
+
+    #[test]
+    fn test_recurrence_iter_times() {
+        let start = now_fixed();
+        let spec = RecurrenceSpec {
+            interval: Duration::minutes(30),
+            limit: Limit::Times(3),
+        };
+        let targets: Vec<_> = spec.iter(start).collect();
+        assert_eq!(targets.len(), 3);
+        assert_eq!((targets[0] - start).num_minutes(), 30);
+        assert_eq!((targets[1] - start).num_minutes(), 60);
+        assert_eq!((targets[2] - start).num_minutes(), 90);
+    }
+
+    #[test]
+    fn test_recurrence_iter_until() {
+        let start = now_fixed();
+        let spec = RecurrenceSpec {
+            interval: Duration::hours(1),
+            limit: Limit::Until(start + Duration::hours(3) + Duration::minutes(30)),
+        };
+        let targets: Vec<_> = spec.iter(start).collect();
+        // 1h, 2h, 3h fit; 4h exceeds the limit.
+        assert_eq!(targets.len(), 3);
+        assert_eq!((targets[2] - start).num_hours(), 3);
+    }
+
+    // parse_offset / timezone tests
+
+    #[test]
+    fn test_parse_offset_forms() {
+        assert_eq!(parse_offset("+0900"), FixedOffset::east_opt(9 * 3600));
+        assert_eq!(parse_offset("-0500"), FixedOffset::east_opt(-5 * 3600));
+        assert_eq!(parse_offset("Z"), FixedOffset::east_opt(0));
+        assert!(parse_offset("0900").is_none());
+        assert!(parse_offset("+09").is_none());
+        assert!(parse_offset("+09xy").is_none());
+    }
+
+    #[test]
+    fn test_hhmm_in_fixed_offset() {
+        // 09:00 in UTC+9 is 00:00 UTC regardless of the local zone.
+        // now is 08:00 in UTC+9 (Feb 19 23:00 UTC), so 09:00 is still ahead.
+        let now = chrono::Utc
+            .with_ymd_and_hms(2026, 2, 19, 23, 0, 0)
+            .unwrap()
+            .with_timezone(&FixedOffset::east_opt(9 * 3600).unwrap());
+        let end = parse_end_time(&args(&["09:00"]), now).unwrap();
+        let utc = end.with_timezone(&chrono::Utc);
+        assert_eq!(utc.format("%Y-%m-%d %H:%M:%S").to_string(), "2026-02-20 00:00:00");
+    }
+
+    #[test]
+    fn test_recurrence_iter_nonpositive_interval_until_terminates() {
+        let start = now_fixed();
+        let spec = RecurrenceSpec {
+            interval: Duration::zero(),
+            limit: Limit::Until(start + Duration::hours(3)),
+        };
+        // Must not loop forever: a zero interval yields nothing under Until.
+        assert!(spec.iter(start).next().is_none());
+    }
+
+    // validate_format tests
+
+    #[test]
+    fn test_validate_format_ok() {
+        assert!(validate_format("%a %b %d %I:%M %p").is_ok());
+    }
+
+    #[test]
+    fn test_validate_format_bad() {
+        assert!(validate_format("%Q").is_err());
+    }
+
+    #[test]
+    fn test_render_eta_custom_format() {
+        let end = make_dt(2026, 2, 20, 14, 30, 45);
+        let now = make_dt(2026, 2, 20, 10, 0, 0);
+        assert_eq!(render_eta(&end, &now, Some("%H:%M")), "14:30");
+        assert_eq!(render_eta(&end, &now, None), "14:30:45");
     }
 
     // sleep_until_without_progress tests
@@ -1,5 +1,5 @@
-use chrono::Local;
-use zzsleep::{parse_end_time, sleep_until_with_progress};
+use chrono::{Local, Utc};
+use zzsleep::{parse_end_time, sleep_until, split_args, validate_format};
 
 #[tokio::main(flavor = "current_thread")]
 async fn main() {
@@ -14,15 +14,52 @@ async fn main() {
         eprintln!("  zz 2h 5m       # 2 hours 5 minutes");
         eprintln!("  zz 5m 30s      # 5 minutes 30 seconds");
         eprintln!("  zz 1h 30m 45s  # 1 hour 30 minutes 45 seconds");
+        eprintln!("  zz 1w 2d       # 1 week 2 days");
         eprintln!("  zz 12:30       # until 12:30 today (tomorrow if past)");
         eprintln!("  zz 12:30:45    # until 12:30:45 today (tomorrow if past)");
         eprintln!("  zz 20260220T123000+0900  # ISO 8601 with timezone");
         eprintln!("  zz 20260220T123000Z      # ISO 8601 UTC");
+        eprintln!("  zz --every 30m --times 5 # repeat every 30 minutes, 5 times");
+        eprintln!("  zz --every 1h --until 18:00  # repeat hourly until 18:00");
+        eprintln!("  zz 09:00 --tz +0900          # 09:00 in UTC+9, converted to local");
         std::process::exit(1);
     }
 
+    let opts = match split_args(&args) {
+        Ok(o) => o,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    };
     let now = Local::now();
-    let end_time = match parse_end_time(&args, now) {
+
+    if let Some(fmt) = &opts.format
+        && let Err(e) = validate_format(fmt)
+    {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    }
+    let format = opts.format.as_deref();
+
+    if let Some(spec) = opts.recurrence {
+        let targets: Vec<_> = spec.iter(now).collect();
+        let total = targets.len();
+        for (i, target) in targets.into_iter().enumerate() {
+            sleep_until(target, opts.quiet, format).await;
+            if i + 1 < total {
+                println!("cycle {}/{} done", i + 1, total);
+            }
+        }
+        return;
+    }
+
+    // When a target timezone is given, resolve clock-time targets in that zone.
+    let parsed = match opts.tz {
+        Some(offset) => parse_end_time(&opts.time_args, Utc::now().with_timezone(&offset)),
+        None => parse_end_time(&opts.time_args, now),
+    };
+    let end_time = match parsed {
         Ok(t) => t,
         Err(e) => {
             eprintln!("error: {}", e);
@@ -30,5 +67,5 @@ async fn main() {
         }
     };
 
-    sleep_until_with_progress(end_time).await;
+    sleep_until(end_time, opts.quiet, format).await;
 }